@@ -0,0 +1,149 @@
+//! Self-contained "archive" output mode.
+//!
+//! Because `validate_resource_urls` forces every `src`/`href` other than local
+//! `<img>` sources to be an absolute URL, a generated site breaks the moment
+//! those remote resources disappear.
+//! Archive mode fetches each referenced asset and inlines it as a
+//! `data:<mime>;base64,<...>` URI so the HTML renders offline.
+
+use crate::Article;
+use anyhow::Result;
+use base64::Engine;
+use blake2::{Blake2s256, Digest};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+// Genuine asset references. `src` always points at an embedded resource, while
+// for `href` we only inline `<link>` elements (stylesheets, icons); anchor
+// `href`s are navigation and must be left alone.
+static RE_SRC_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(\s)src=["']([^"']*)["']"#).unwrap());
+static RE_LINK_HREF: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(<link\b[^>]*?href=["'])([^"']*)(["'])"#).unwrap());
+
+/// Maps a URL's file extension to a best-guess MIME type.
+fn mime_from_extension(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let ext = path.rsplit('.').next().unwrap_or("").to_ascii_lowercase();
+    let mime = match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "css" => "text/css",
+        "js" => "text/javascript",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// Resolves the media type from the `Content-Type` header, falling back to the
+/// URL extension and finally to magic-byte sniffing.
+fn guess_mime(url: &str, content_type: Option<&str>, bytes: &[u8]) -> String {
+    if let Some(ct) = content_type {
+        let ct = ct.split(';').next().unwrap_or("").trim();
+        if !ct.is_empty() {
+            return ct.to_string();
+        }
+    }
+    if let Some(mime) = mime_from_extension(url) {
+        return mime.to_string();
+    }
+    infer::get(bytes)
+        .map(|kind| kind.mime_type().to_string())
+        .unwrap_or_else(|| "application/octet-stream".to_string())
+}
+
+/// A short, stable cache key for a URL within a single build.
+fn url_hash(url: &str) -> String {
+    let mut hasher = Blake2s256::new();
+    hasher.update(url.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Fetches a URL, returning its `Content-Type` (if any) and raw bytes.
+fn fetch(client: &reqwest::blocking::Client, url: &str) -> Result<(Option<String>, Vec<u8>)> {
+    let response = client.get(url).send()?.error_for_status()?;
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+    let bytes = response.bytes()?.to_vec();
+    Ok((content_type, bytes))
+}
+
+/// Rewrites every fetchable absolute resource URL in `html` to a data URI,
+/// reusing `cache` so each distinct URL is downloaded at most once per build.
+fn inline_in_html(
+    html: &str,
+    client: &reqwest::blocking::Client,
+    cache: &mut HashMap<String, String>,
+) -> String {
+    // Pass 1: every `src` attribute (images, scripts, media). The leading
+    // whitespace capture keeps this from matching `data-src` and friends.
+    let html = RE_SRC_ATTR.replace_all(html, |caps: &regex::Captures| {
+        match to_data_uri(&caps[2], client, cache) {
+            Some(data_uri) => format!("{}src=\"{}\"", &caps[1], data_uri),
+            None => caps[0].to_string(),
+        }
+    });
+
+    // Pass 2: `href` only on `<link>` elements, never on navigational anchors.
+    RE_LINK_HREF
+        .replace_all(&html, |caps: &regex::Captures| {
+            match to_data_uri(&caps[2], client, cache) {
+                Some(data_uri) => format!("{}{}{}", &caps[1], data_uri, &caps[3]),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Fetches a single resource URL and returns its data URI, or `None` when the
+/// URL should be left untouched (already inlined, empty, or a failed fetch).
+fn to_data_uri(
+    url: &str,
+    client: &reqwest::blocking::Client,
+    cache: &mut HashMap<String, String>,
+) -> Option<String> {
+    if url.is_empty() || url.starts_with("data:") {
+        return None;
+    }
+
+    let key = url_hash(url);
+    if let Some(cached) = cache.get(&key) {
+        return Some(cached.clone());
+    }
+
+    match fetch(client, url) {
+        Ok((content_type, bytes)) => {
+            let mime = guess_mime(url, content_type.as_deref(), &bytes);
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            let data_uri = format!("data:{};base64,{}", mime, encoded);
+            cache.insert(key, data_uri.clone());
+            Some(data_uri)
+        }
+        Err(e) => {
+            // Leave the original URL in place rather than aborting the build.
+            eprintln!("-> Could not archive asset '{}': {}", url, e);
+            None
+        }
+    }
+}
+
+/// Inlines every remote asset referenced by the given articles' HTML.
+pub fn inline_remote_assets(articles: &mut [Article]) -> Result<()> {
+    let client = reqwest::blocking::Client::new();
+    let mut cache: HashMap<String, String> = HashMap::new();
+    for article in articles {
+        article.html_content = inline_in_html(&article.html_content, &client, &mut cache);
+    }
+    Ok(())
+}
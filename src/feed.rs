@@ -0,0 +1,195 @@
+//! Syndication feed generation (RSS 2.0 and Atom).
+//!
+//! Every `Article` already carries the metadata a reader's feed client needs,
+//! so this module turns the parsed article list into `feed.xml` (RSS 2.0) and,
+//! when enabled, `atom.xml`. Both are driven by the same site `settings` that
+//! the rest of `generate_site` uses.
+
+use crate::Article;
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use html_escape::encode_text;
+use std::path::Path;
+
+/// Site-wide context needed to build a feed, pulled from the `settings` map.
+struct FeedContext {
+    title: String,
+    description: String,
+    base_url: String,
+}
+
+/// Builds the canonical link/guid for an article.
+///
+/// Prefers the article's explicit `link_url`; otherwise falls back to joining
+/// the site `base_url` with the article slug.
+fn article_link(article: &Article, base_url: &str) -> String {
+    article
+        .link_url
+        .clone()
+        .unwrap_or_else(|| format!("{}/{}", base_url.trim_end_matches('/'), article.slug))
+}
+
+/// Wraps text in a CDATA section, escaping any embedded terminator.
+fn cdata(content: &str) -> String {
+    format!("<![CDATA[{}]]>", content.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+/// Converts a `NaiveDate` to an RFC 2822 timestamp at midnight UTC (for RSS `pubDate`).
+fn rfc2822(date: NaiveDate) -> String {
+    date.and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+        .to_rfc2822()
+}
+
+/// Converts a `NaiveDate` to an RFC 3339 timestamp at midnight UTC (for Atom dates).
+fn rfc3339(date: NaiveDate) -> String {
+    date.and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+        .to_rfc3339()
+}
+
+/// Renders the RSS 2.0 document for the given articles.
+fn render_rss(articles: &[Article], ctx: &FeedContext) -> String {
+    let mut items = String::new();
+    for article in articles {
+        let link = article_link(article, &ctx.base_url);
+        items.push_str("    <item>\n");
+        items.push_str(&format!(
+            "      <title>{}</title>\n",
+            encode_text(&article.title)
+        ));
+        items.push_str(&format!("      <link>{}</link>\n", encode_text(&link)));
+        items.push_str(&format!(
+            "      <guid isPermaLink=\"true\">{}</guid>\n",
+            encode_text(&link)
+        ));
+        if !article.description.is_empty() {
+            items.push_str(&format!(
+                "      <description>{}</description>\n",
+                encode_text(&article.description)
+            ));
+        }
+        if let Some(created) = article.created {
+            items.push_str(&format!("      <pubDate>{}</pubDate>\n", rfc2822(created)));
+        }
+        for tag in &article.tags {
+            items.push_str(&format!("      <category>{}</category>\n", encode_text(tag)));
+        }
+        items.push_str(&format!(
+            "      <content:encoded>{}</content:encoded>\n",
+            cdata(&article.html_content)
+        ));
+        items.push_str("    </item>\n");
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+  <channel>
+    <title>{title}</title>
+    <link>{link}</link>
+    <description>{description}</description>
+{items}  </channel>
+</rss>
+"#,
+        title = encode_text(&ctx.title),
+        link = encode_text(&ctx.base_url),
+        description = encode_text(&ctx.description),
+        items = items,
+    )
+}
+
+/// Renders the Atom 1.0 document for the given articles.
+fn render_atom(articles: &[Article], ctx: &FeedContext) -> String {
+    let mut entries = String::new();
+    for article in articles {
+        let link = article_link(article, &ctx.base_url);
+        entries.push_str("  <entry>\n");
+        entries.push_str(&format!(
+            "    <title>{}</title>\n",
+            encode_text(&article.title)
+        ));
+        entries.push_str(&format!("    <link href=\"{}\"/>\n", encode_text(&link)));
+        entries.push_str(&format!("    <id>{}</id>\n", encode_text(&link)));
+        let updated = article.modified.or(article.created);
+        if let Some(updated) = updated {
+            entries.push_str(&format!("    <updated>{}</updated>\n", rfc3339(updated)));
+        }
+        if let Some(created) = article.created {
+            entries.push_str(&format!("    <published>{}</published>\n", rfc3339(created)));
+        }
+        for tag in &article.tags {
+            entries.push_str(&format!("    <category term=\"{}\"/>\n", encode_text(tag)));
+        }
+        entries.push_str(&format!(
+            "    <content type=\"html\">{}</content>\n",
+            cdata(&article.html_content)
+        ));
+        entries.push_str("  </entry>\n");
+    }
+
+    let updated = articles
+        .iter()
+        .filter_map(|a| a.modified.or(a.created))
+        .max()
+        .map(rfc3339)
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>{title}</title>
+  <link href="{link}"/>
+  <id>{link}</id>
+  <updated>{updated}</updated>
+{entries}</feed>
+"#,
+        title = encode_text(&ctx.title),
+        link = encode_text(&ctx.base_url),
+        updated = updated,
+        entries = entries,
+    )
+}
+
+/// Writes `feed.xml` (RSS 2.0) and, unless disabled via `atom` in `settings`,
+/// `atom.xml` into `output_dir`.
+///
+/// Site metadata is read from the `settings` sub-map: `title`, `description`,
+/// and `base_url`. The `atom` flag (default `true`) toggles the Atom output.
+pub fn generate_feeds(
+    articles: &[Article],
+    settings: &serde_json::Map<String, serde_json::Value>,
+    output_dir: &Path,
+) -> Result<()> {
+    let ctx = FeedContext {
+        title: settings
+            .get("title")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        description: settings
+            .get("description")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        base_url: settings
+            .get("base_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    };
+
+    std::fs::write(output_dir.join("feed.xml"), render_rss(articles, &ctx))?;
+
+    let emit_atom = settings
+        .get("atom")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    if emit_atom {
+        std::fs::write(output_dir.join("atom.xml"), render_atom(articles, &ctx))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Local image processing: responsive, content-hashed thumbnails.
+//!
+//! Scans each article for `<img>` tags whose `src` is a local path, decodes the
+//! source with the `image` crate, emits one or more resized variants under
+//! content-hashed filenames, and rewrites the tag to use `srcset`/`sizes` plus
+//! intrinsic `width`/`height` to avoid layout shift. SVGs and animated formats
+//! are skipped, and existing variants are reused when the source is unchanged.
+
+use crate::{Article, RE_IMG_SRC};
+use anyhow::Result;
+use base64::Engine;
+use blake2::{Blake2s256, Digest};
+use std::path::Path;
+
+/// A short, URL-safe content hash for cache-busting filenames.
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = Blake2s256::new();
+    hasher.update(bytes);
+    let digest = hasher.finalize();
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&digest[..9])
+}
+
+/// Returns true for formats we never rasterize (vector or potentially animated).
+fn is_skippable(src: &str) -> bool {
+    let path = src.split(['?', '#']).next().unwrap_or(src);
+    let ext = path.rsplit('.').next().unwrap_or("").to_ascii_lowercase();
+    // Only bitmap stills are safe to resize; skip everything else.
+    !matches!(ext.as_str(), "png" | "jpg" | "jpeg")
+}
+
+/// Returns true if `tag` already declares the given attribute.
+fn has_attr(tag: &str, attr: &str) -> bool {
+    tag.contains(&format!(" {}=", attr))
+}
+
+/// Processes a single local `<img>`, returning the rewritten tag on success.
+///
+/// Returns `None` (keep the original tag) when the source is missing, is a
+/// skippable format, or fails to decode.
+fn process_one(tag: &str, src: &str, source_dir: &Path, widths: &[u32], sizes: &str, output_dir: &Path) -> Option<String> {
+    if is_skippable(src) {
+        return None;
+    }
+
+    let source_path = source_dir.join(src);
+    let bytes = std::fs::read(&source_path).ok()?;
+    let image = image::load_from_memory(&bytes).ok()?;
+    let (orig_w, orig_h) = image::GenericImageView::dimensions(&image);
+
+    let ext = src
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(src)
+        .rsplit('.')
+        .next()
+        .unwrap_or("png")
+        .to_ascii_lowercase();
+    let hash = content_hash(&bytes);
+
+    // Target widths never exceed the source; the source width is always emitted.
+    let mut targets: Vec<u32> = widths.iter().copied().filter(|w| *w < orig_w).collect();
+    targets.push(orig_w);
+    targets.sort_unstable();
+    targets.dedup();
+
+    let mut srcset = Vec::new();
+    for width in &targets {
+        let height = ((orig_h as u64 * *width as u64) / orig_w as u64).max(1) as u32;
+        let filename = format!("{}-{}.{}", hash, width, ext);
+        let out_path = output_dir.join(&filename);
+        // Reuse variants left by a previous build (filename is content-addressed).
+        if !out_path.exists() {
+            let variant = if *width >= orig_w {
+                image.clone()
+            } else {
+                image.resize(*width, height, image::imageops::FilterType::Lanczos3)
+            };
+            if variant.save(&out_path).is_err() {
+                return None;
+            }
+        }
+        srcset.push(format!("{} {}w", filename, width));
+    }
+
+    let largest = format!("{}-{}.{}", hash, orig_w, ext);
+    let mut rewritten = tag.replace(src, &largest);
+
+    let mut additions = String::new();
+    if !has_attr(&rewritten, "srcset") {
+        additions.push_str(&format!(" srcset=\"{}\"", srcset.join(", ")));
+    }
+    if !has_attr(&rewritten, "sizes") {
+        additions.push_str(&format!(" sizes=\"{}\"", sizes));
+    }
+    if !has_attr(&rewritten, "width") {
+        additions.push_str(&format!(" width=\"{}\"", orig_w));
+    }
+    if !has_attr(&rewritten, "height") {
+        additions.push_str(&format!(" height=\"{}\"", orig_h));
+    }
+
+    let (cut, tail) = match rewritten.rfind("/>") {
+        Some(pos) => (pos, "/>"),
+        None => (rewritten.rfind('>')?, ">"),
+    };
+    rewritten = format!("{}{}{}", &rewritten[..cut], additions, tail);
+    Some(rewritten)
+}
+
+/// Rewrites every local `<img>` in the given articles to responsive variants.
+pub fn process_images(
+    articles: &mut [Article],
+    source_dir: &Path,
+    widths: &[u32],
+    sizes: &str,
+    output_dir: &Path,
+) -> Result<()> {
+    for article in articles {
+        article.html_content = RE_IMG_SRC
+            .replace_all(&article.html_content, |caps: &regex::Captures| {
+                let tag = &caps[0];
+                let src = &caps[1];
+                // Leave absolute/remote and data URIs alone; only local paths are processed.
+                if src.is_empty() || src.starts_with("data:") || url::Url::parse(src).is_ok() {
+                    return tag.to_string();
+                }
+                match process_one(tag, src, source_dir, widths, sizes, output_dir) {
+                    Some(rewritten) => rewritten,
+                    None => {
+                        eprintln!("-> Skipping image '{}': could not process", src);
+                        tag.to_string()
+                    }
+                }
+            })
+            .into_owned();
+    }
+    Ok(())
+}
@@ -3,17 +3,25 @@ use chrono::{DateTime, NaiveDate, Utc};
 use gray_matter::{engine::YAML, Matter};
 use html_escape::encode_text;
 use once_cell::sync::Lazy;
-use pulldown_cmark::{html, Options, Parser};
+use pulldown_cmark::{html, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag};
 use regex::Regex;
 use resvg::{tiny_skia, usvg};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
+use syntect::highlighting::ThemeSet;
+use syntect::html::{css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 use tera::Tera;
 use url::Url;
 use walkdir::WalkDir;
 
+mod archive;
+mod feed;
+mod images;
+
 // --- Statically Compiled Regexes ---
 static RE_FIRST_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"https?://[^\s()<>]+").unwrap());
 static RE_BODY_TAGS: Lazy<Regex> = Lazy::new(|| Regex::new(r"#(\p{L}[\p{L}\p{N}-]*)").unwrap());
@@ -23,6 +31,24 @@ static RE_FILENAME_DATE: Lazy<Regex> = Lazy::new(|| {
 static RE_HTML_RESOURCES: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"(?:src|href)=["'](.*?)["']"#).unwrap());
 
+// Default syntax definitions, loaded once for server-side code highlighting.
+static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+
+// Matches an opening anchor tag and captures its `href` value.
+static RE_ANCHOR_TAG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"<a\s[^>]*?href=["']([^"']*)["'][^>]*>"#).unwrap());
+
+// Matches an `<img>` tag and captures its `src` value. Shared with the image
+// processing pass so the pattern is defined exactly once.
+pub(crate) static RE_IMG_SRC: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"<img\s[^>]*?src=["']([^"']*)["'][^>]*>"#).unwrap());
+
+// Matches an HTML tag, used to strip markup before counting words.
+static RE_HTML_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]+>").unwrap());
+
+/// The default reading speed, in words per minute.
+pub const DEFAULT_WORDS_PER_MINUTE: usize = 200;
+
 /// Represents a social media share link.
 #[derive(Debug, Serialize)]
 pub struct ShareLink {
@@ -45,6 +71,22 @@ pub struct Article {
     pub content: String,
     pub slug: String,
     pub share_links: Vec<ShareLink>,
+    pub toc: Vec<TocEntry>,
+    pub word_count: usize,
+    pub reading_time_minutes: usize,
+}
+
+/// A single entry in an article's table of contents.
+#[derive(Debug, Serialize)]
+pub struct TocEntry {
+    /// Heading depth (1 for `<h1>`, 2 for `<h2>`, ...).
+    pub level: usize,
+    /// The heading's plain-text content.
+    pub title: String,
+    /// The stable anchor slug used in the heading `id` and permalink.
+    pub slug: String,
+    /// Nested headings logically contained under this one.
+    pub children: Vec<TocEntry>,
 }
 
 /// Represents the optional frontmatter fields in a Markdown file.
@@ -58,6 +100,14 @@ struct Frontmatter {
     link_url: Option<String>,
 }
 
+/// Summarizes a single tag for the tag-index page.
+#[derive(Debug, Serialize)]
+struct TagSummary {
+    name: String,
+    slug: String,
+    count: usize,
+}
+
 /// Represents an entry in the client-side search index.
 #[derive(Debug, Serialize)]
 struct SearchEntry<'a> {
@@ -68,6 +118,61 @@ struct SearchEntry<'a> {
     slug: &'a str,
 }
 
+/// Converts an arbitrary string into a URL-friendly slug.
+///
+/// Lowercases the input, replaces every run of non-alphanumeric characters with
+/// a single `-`, and trims leading/trailing dashes. Unicode alphanumerics are
+/// preserved (lowercased), matching the Unicode-aware tag handling elsewhere.
+fn slugify(input: &str) -> String {
+    let mut slug = String::new();
+    let mut prev_dash = false;
+    for ch in input.chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+            prev_dash = false;
+        } else if !prev_dash {
+            slug.push('-');
+            prev_dash = true;
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Counts words in source content, ignoring fenced code blocks and HTML tags.
+///
+/// Tokens are split on Unicode whitespace; only tokens containing at least one
+/// alphanumeric character are counted, so stray punctuation is not tallied.
+fn count_words(content: &str) -> usize {
+    let mut prose = String::new();
+    let mut in_fence = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        prose.push_str(line);
+        prose.push('\n');
+    }
+
+    let stripped = RE_HTML_TAG.replace_all(&prose, " ");
+    stripped
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Computes word count and reading time (rounded up, at least 1 minute).
+fn reading_metrics(content: &str, words_per_minute: usize) -> (usize, usize) {
+    let wpm = words_per_minute.max(1);
+    let word_count = count_words(content);
+    let reading_time_minutes = word_count.div_ceil(wpm).max(1);
+    (word_count, reading_time_minutes)
+}
+
 /// Extracts the first absolute URL from a string.
 fn extract_first_url(content: &str) -> Option<String> {
     RE_FIRST_URL.find(content).map(|m| m.as_str().to_string())
@@ -109,6 +214,14 @@ fn extract_metadata_from_path(path: &Path) -> (String, Option<NaiveDate>) {
 
 /// Validates that all `src` and `href` attributes in an HTML string point to absolute URLs.
 fn validate_resource_urls(html_content: &str, source_file: &Path) -> Result<()> {
+    // Local `<img>` sources are permitted: they are later decoded and emitted as
+    // responsive, content-hashed variants by the image-processing pass.
+    let local_images: std::collections::HashSet<&str> = RE_IMG_SRC
+        .captures_iter(html_content)
+        .map(|cap| cap.get(1).unwrap().as_str())
+        .filter(|src| !src.is_empty() && !src.starts_with("data:") && Url::parse(src).is_err())
+        .collect();
+
     for cap in RE_HTML_RESOURCES.captures_iter(html_content) {
         let url_str = &cap[1];
         // Skip empty URLs, page-local anchors, or data URIs
@@ -117,12 +230,79 @@ fn validate_resource_urls(html_content: &str, source_file: &Path) -> Result<()>
         }
         // Use the `url` crate to robustly check if the URL is absolute.
         if Url::parse(url_str).is_err() {
+            if local_images.contains(url_str) {
+                continue;
+            }
             return Err(anyhow!("Validation failed for file '{}': Found relative or invalid resource link: '{}'. All resource links (src/href) must be absolute URLs.", source_file.display(), url_str));
         }
     }
     Ok(())
 }
 
+/// Determines whether an `href` points to an external host.
+///
+/// Page-local anchors, `mailto:`/`tel:`/`data:` URIs, and relative links are
+/// all treated as internal. An absolute URL is external when its host differs
+/// from the site's configured host (or when no host is configured).
+fn is_external_link(href: &str, base_host: Option<&str>) -> bool {
+    if href.is_empty() || href.starts_with('#') {
+        return false;
+    }
+    let lower = href.to_ascii_lowercase();
+    if lower.starts_with("mailto:") || lower.starts_with("tel:") || lower.starts_with("data:") {
+        return false;
+    }
+    match Url::parse(href) {
+        Ok(url) => match (url.host_str(), base_host) {
+            (Some(host), Some(base)) => !host.eq_ignore_ascii_case(base),
+            (Some(_), None) => true,
+            _ => false,
+        },
+        // Relative URLs fail to parse as absolute and are considered internal.
+        Err(_) => false,
+    }
+}
+
+/// Rewrites external anchor tags in `html` to harden them for readers and SEO.
+///
+/// External links gain `rel="noopener noreferrer"`, plus `nofollow` when
+/// `no_follow` is set and `target="_blank"` when `new_tab` is set. Internal,
+/// anchor, `mailto:`, and `data:` links are left untouched, as are tags that
+/// already carry the corresponding attribute.
+fn harden_external_links(html: &str, base_url: &str, new_tab: bool, no_follow: bool) -> String {
+    let base_host = Url::parse(base_url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string));
+
+    RE_ANCHOR_TAG
+        .replace_all(html, |caps: &regex::Captures| {
+            let tag = &caps[0];
+            if !is_external_link(&caps[1], base_host.as_deref()) {
+                return tag.to_string();
+            }
+
+            let mut additions = String::new();
+            if !tag.contains(" rel=") {
+                let rel = if no_follow {
+                    "noopener noreferrer nofollow"
+                } else {
+                    "noopener noreferrer"
+                };
+                additions.push_str(&format!(" rel=\"{}\"", rel));
+            }
+            if new_tab && !tag.contains(" target=") {
+                additions.push_str(" target=\"_blank\"");
+            }
+
+            if additions.is_empty() {
+                return tag.to_string();
+            }
+            let insert_at = tag.rfind('>').unwrap();
+            format!("{}{}{}", &tag[..insert_at], additions, &tag[insert_at..])
+        })
+        .into_owned()
+}
+
 /// Generates a list of social sharing links based on provider templates.
 ///
 /// Note: This function performs several string allocations for URL encoding and
@@ -167,17 +347,25 @@ fn generate_share_links(
 }
 
 /// Parses a file (Markdown or plain text) into an `Article`.
-fn parse_file(path: &Path, share_providers: &[(String, String)]) -> Result<Article> {
+fn parse_file(
+    path: &Path,
+    share_providers: &[(String, String)],
+    words_per_minute: usize,
+) -> Result<Article> {
     let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("");
     match extension {
-        "md" => parse_markdown_file(path, share_providers),
-        "txt" => parse_text_file(path, share_providers),
+        "md" => parse_markdown_file(path, share_providers, words_per_minute),
+        "txt" => parse_text_file(path, share_providers, words_per_minute),
         _ => Err(anyhow!("Unsupported file type: {}", path.display())),
     }
 }
 
 /// Parses a plain text file into an `Article`.
-fn parse_text_file(path: &Path, share_providers: &[(String, String)]) -> Result<Article> {
+fn parse_text_file(
+    path: &Path,
+    share_providers: &[(String, String)],
+    words_per_minute: usize,
+) -> Result<Article> {
     let (path_title, path_date) = extract_metadata_from_path(path);
     let content = fs::read_to_string(path)?;
     let metadata = fs::metadata(path)?;
@@ -195,6 +383,7 @@ fn parse_text_file(path: &Path, share_providers: &[(String, String)]) -> Result<
     let tags = extract_body_tags(&content);
     let share_links =
         generate_share_links(share_providers, &link_url, &path_title, &content, &tags);
+    let (word_count, reading_time_minutes) = reading_metrics(&content, words_per_minute);
 
     Ok(Article {
         title: path_title,
@@ -207,11 +396,183 @@ fn parse_text_file(path: &Path, share_providers: &[(String, String)]) -> Result<
         modified: Some(modified_date),
         link_url,
         share_links,
+        toc: Vec::new(),
+        word_count,
+        reading_time_minutes,
     })
 }
 
+/// Highlights a single fenced or indented code block into class-annotated HTML.
+///
+/// The language token is resolved against the default syntax set, falling back
+/// to plain text when it is empty or unknown. Highlighting is class-based, so
+/// the actual colours come from the theme CSS emitted by `write_syntax_css`.
+fn highlight_block(code: &str, lang: &str) -> String {
+    let syntax_set = &SYNTAX_SET;
+    let syntax = if lang.is_empty() {
+        syntax_set.find_syntax_plain_text()
+    } else {
+        syntax_set
+            .find_syntax_by_token(lang)
+            .unwrap_or_else(|| syntax_set.find_syntax_plain_text())
+    };
+
+    let mut generator =
+        ClassedHTMLGenerator::new_with_class_style(syntax, syntax_set, ClassStyle::Spaced);
+    for line in LinesWithEndings::from(code) {
+        // A malformed line should not abort the whole render; emit what we have.
+        let _ = generator.parse_html_for_line_which_includes_newline(line);
+    }
+    format!("<pre class=\"code\"><code>{}</code></pre>\n", generator.finalize())
+}
+
+/// Walks a `pulldown_cmark` event stream, replacing each fenced/indented code
+/// block with a syntax-highlighted HTML block while passing everything else
+/// through untouched.
+fn highlight_code_blocks<'a>(parser: impl Iterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+    let mut in_code_block = false;
+    let mut lang = String::new();
+    let mut code = String::new();
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                in_code_block = true;
+                // The fence info string may carry extra tokens (e.g. "rust,ignore").
+                lang = info.split([' ', ',']).next().unwrap_or("").to_string();
+                code.clear();
+            }
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                in_code_block = true;
+                lang.clear();
+                code.clear();
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                in_code_block = false;
+                events.push(Event::Html(highlight_block(&code, &lang).into()));
+            }
+            Event::Text(text) if in_code_block => code.push_str(&text),
+            other => events.push(other),
+        }
+    }
+    events
+}
+
+/// Converts a `HeadingLevel` to its numeric depth (`H1` -> 1, ..., `H6` -> 6).
+fn heading_depth(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Produces a collision-free slug, appending `-1`, `-2`, ... on repeats.
+///
+/// Used for both heading anchors and tag-page filenames so that distinct inputs
+/// which slugify identically never clobber one another.
+fn unique_slug(title: &str, counts: &mut std::collections::HashMap<String, usize>) -> String {
+    let base = slugify(title);
+    let base = if base.is_empty() {
+        "section".to_string()
+    } else {
+        base
+    };
+    let seen = counts.entry(base.clone()).or_insert(0);
+    let slug = if *seen == 0 {
+        base.clone()
+    } else {
+        format!("{}-{}", base, seen)
+    };
+    *seen += 1;
+    slug
+}
+
+/// Inserts a TOC entry into the nested outline, descending into the last entry
+/// whenever it sits higher in the heading hierarchy (a smaller level number).
+fn insert_toc_entry(entries: &mut Vec<TocEntry>, entry: TocEntry) {
+    match entries.last_mut() {
+        Some(last) if last.level < entry.level => insert_toc_entry(&mut last.children, entry),
+        _ => entries.push(entry),
+    }
+}
+
+/// Rewrites headings in the event stream to carry stable `id` anchors and a
+/// self-referencing permalink, and builds the nested table of contents.
+///
+/// Code blocks have already been collapsed into `Event::Html` by
+/// `highlight_code_blocks`, so headings appearing here are never inside fences.
+fn extract_headings_and_toc<'a>(events: Vec<Event<'a>>) -> (Vec<Event<'a>>, Vec<TocEntry>) {
+    let mut out = Vec::with_capacity(events.len());
+    let mut toc = Vec::new();
+    let mut slug_counts = std::collections::HashMap::new();
+
+    let mut iter = events.into_iter();
+    while let Some(event) = iter.next() {
+        match event {
+            Event::Start(Tag::Heading(level, _, _)) => {
+                let depth = heading_depth(level);
+                let mut inner = Vec::new();
+                let mut title = String::new();
+                for inner_event in iter.by_ref() {
+                    if matches!(inner_event, Event::End(Tag::Heading(..))) {
+                        break;
+                    }
+                    if let Event::Text(t) | Event::Code(t) = &inner_event {
+                        title.push_str(t);
+                    }
+                    inner.push(inner_event);
+                }
+
+                let slug = unique_slug(&title, &mut slug_counts);
+                insert_toc_entry(
+                    &mut toc,
+                    TocEntry {
+                        level: depth,
+                        title: title.clone(),
+                        slug: slug.clone(),
+                        children: Vec::new(),
+                    },
+                );
+
+                out.push(Event::Html(format!("<{} id=\"{}\">", level, slug).into()));
+                out.extend(inner);
+                out.push(Event::Html(
+                    format!("<a class=\"anchor\" href=\"#{}\">#</a></{}>", slug, level).into(),
+                ));
+            }
+            other => out.push(other),
+        }
+    }
+
+    (out, toc)
+}
+
+/// Writes the highlighting theme's CSS (class-based) to `syntax.css`.
+///
+/// The theme is looked up by name in syntect's default set, falling back to
+/// `InspiredGitHub` when the configured name is unknown.
+fn write_syntax_css(theme_name: &str, output_dir: &Path) -> Result<()> {
+    let theme_set = ThemeSet::load_defaults();
+    let theme = theme_set
+        .themes
+        .get(theme_name)
+        .unwrap_or_else(|| &theme_set.themes["InspiredGitHub"]);
+    let css = css_for_theme_with_class_style(theme, ClassStyle::Spaced)?;
+    fs::write(output_dir.join("syntax.css"), css)?;
+    Ok(())
+}
+
 /// Parses a Markdown file with optional YAML frontmatter into an `Article`.
-fn parse_markdown_file(path: &Path, share_providers: &[(String, String)]) -> Result<Article> {
+fn parse_markdown_file(
+    path: &Path,
+    share_providers: &[(String, String)],
+    words_per_minute: usize,
+) -> Result<Article> {
     let (path_title, path_date) = extract_metadata_from_path(path);
     let content = fs::read_to_string(path)?;
     let metadata = fs::metadata(path)?;
@@ -258,13 +619,16 @@ fn parse_markdown_file(path: &Path, share_providers: &[(String, String)]) -> Res
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
     let parser = Parser::new_ext(&markdown_content, options);
+    let events = highlight_code_blocks(parser);
+    let (events, toc) = extract_headings_and_toc(events);
     let mut html_content = String::new();
-    html::push_html(&mut html_content, parser);
+    html::push_html(&mut html_content, events.into_iter());
     validate_resource_urls(&html_content, path)?;
 
     let slug = path.file_stem().unwrap().to_string_lossy().to_string();
     let share_links =
         generate_share_links(share_providers, &link_url, &title, &markdown_content, &tags);
+    let (word_count, reading_time_minutes) = reading_metrics(&markdown_content, words_per_minute);
 
     Ok(Article {
         title,
@@ -277,6 +641,9 @@ fn parse_markdown_file(path: &Path, share_providers: &[(String, String)]) -> Res
         modified: Some(modified_date),
         link_url,
         share_links,
+        toc,
+        word_count,
+        reading_time_minutes,
     })
 }
 
@@ -286,6 +653,8 @@ fn parse_markdown_file(path: &Path, share_providers: &[(String, String)]) -> Res
 /// # Arguments
 /// * `source_paths` - A slice of `PathBuf` pointing to directories or files to scan.
 /// * `share_providers` - A slice of tuples containing share provider names and URL templates.
+/// * `words_per_minute` - Reading speed used to estimate each article's reading time
+///   (see `DEFAULT_WORDS_PER_MINUTE`).
 ///
 /// # Returns
 /// A `Result` containing a vector of `Article`s, sorted by creation date (descending),
@@ -294,6 +663,7 @@ fn parse_markdown_file(path: &Path, share_providers: &[(String, String)]) -> Res
 pub fn find_and_parse_articles(
     source_paths: &[PathBuf],
     share_providers: &[(String, String)],
+    words_per_minute: usize,
 ) -> Result<Vec<Article>> {
     let mut articles = Vec::new();
     for source_path in source_paths {
@@ -301,7 +671,7 @@ pub fn find_and_parse_articles(
             if let Some(ext) = entry.path().extension().and_then(|s| s.to_str()) {
                 if ext == "md" || ext == "txt" {
                     println!("Processing: {}", entry.path().display());
-                    match parse_file(entry.path(), share_providers) {
+                    match parse_file(entry.path(), share_providers, words_per_minute) {
                         Ok(article) => articles.push(article),
                         Err(e) => eprintln!("-> Skipping file {}: {}", entry.path().display(), e),
                     }
@@ -387,6 +757,57 @@ pub fn generate_site(
 ) -> Result<()> {
     fs::create_dir_all(output_dir)?;
 
+    let mut articles = articles;
+
+    // Harden external links in each article's rendered HTML.
+    if let Some(settings_map) = settings.get("settings").and_then(|v| v.as_object()) {
+        let base_url = settings_map
+            .get("base_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let new_tab = settings_map
+            .get("external_links_new_tab")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let no_follow = settings_map
+            .get("external_links_no_follow")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        for article in &mut articles {
+            article.html_content =
+                harden_external_links(&article.html_content, base_url, new_tab, no_follow);
+        }
+    }
+
+    // Opt-in archive mode: inline remote assets as data URIs for offline use.
+    let archive_mode = settings
+        .get("settings")
+        .and_then(|v| v.get("archive"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if archive_mode {
+        archive::inline_remote_assets(&mut articles)?;
+    }
+
+    // Generate responsive, content-hashed variants for local images.
+    if let Some(settings_map) = settings.get("settings").and_then(|v| v.as_object()) {
+        let source_dir = settings_map
+            .get("image_source_dir")
+            .and_then(|v| v.as_str())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let widths: Vec<u32> = settings_map
+            .get("image_widths")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|w| w.as_u64().map(|w| w as u32)).collect())
+            .unwrap_or_else(|| vec![480, 800, 1200]);
+        let sizes = settings_map
+            .get("image_sizes")
+            .and_then(|v| v.as_str())
+            .unwrap_or("100vw");
+        images::process_images(&mut articles, &source_dir, &widths, sizes, output_dir)?;
+    }
+
     // Extract title and generate favicons
     if let Some(settings_map) = settings.get("settings").and_then(|v| v.as_object()) {
         if let Some(title_val) = settings_map.get("title") {
@@ -410,6 +831,59 @@ pub fn generate_site(
     let search_json = serde_json::to_string(&search_index)?;
     fs::write(output_dir.join("search_index.json"), search_json)?;
 
+    // Generate syndication feeds (RSS 2.0 and, when enabled, Atom).
+    if let Some(settings_map) = settings.get("settings").and_then(|v| v.as_object()) {
+        feed::generate_feeds(&articles, settings_map, output_dir)?;
+
+        // Emit the highlighting theme's stylesheet for the rendered code blocks.
+        let theme_name = settings_map
+            .get("syntax_theme")
+            .and_then(|v| v.as_str())
+            .unwrap_or("InspiredGitHub");
+        write_syntax_css(theme_name, output_dir)?;
+    }
+
+    // Generate per-tag taxonomy pages by inverting the article->tags relation.
+    let mut tag_map: std::collections::BTreeMap<String, Vec<&Article>> =
+        std::collections::BTreeMap::new();
+    for article in &articles {
+        for tag in &article.tags {
+            tag_map.entry(tag.clone()).or_default().push(article);
+        }
+    }
+    if !tag_map.is_empty() {
+        let tags_dir = output_dir.join("tags");
+        fs::create_dir_all(&tags_dir)?;
+
+        let tag_template = include_str!("../templates/tag.html");
+        let mut tag_summaries = Vec::with_capacity(tag_map.len());
+        let mut slug_counts = std::collections::HashMap::new();
+        for (tag, mut tagged) in tag_map {
+            tagged.sort_by(|a, b| b.created.cmp(&a.created));
+            // Dedup slugs so two tags that slugify identically don't overwrite
+            // each other's page (the map is keyed by the raw tag name).
+            let slug = unique_slug(&tag, &mut slug_counts);
+
+            let mut tag_context = settings.clone();
+            tag_context.insert("tag", &tag);
+            tag_context.insert("articles", &tagged);
+            let tag_html = Tera::one_off(tag_template, &tag_context, true)?;
+            fs::write(tags_dir.join(format!("{}.html", slug)), tag_html)?;
+
+            tag_summaries.push(TagSummary {
+                name: tag,
+                slug,
+                count: tagged.len(),
+            });
+        }
+
+        let index_template = include_str!("../templates/tags.html");
+        let mut index_context = settings.clone();
+        index_context.insert("tags", &tag_summaries);
+        let index_html = Tera::one_off(index_template, &index_context, true)?;
+        fs::write(tags_dir.join("index.html"), index_html)?;
+    }
+
     // Render final HTML
     let mut context = settings.clone();
     context.insert("articles", &articles);
@@ -423,3 +897,44 @@ pub fn generate_site(
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn slugify_normalizes_case_and_separators() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(slugify("  Trim -- Me  "), "trim-me");
+        assert_eq!(slugify("C++"), "c");
+        assert_eq!(slugify("Rust"), "rust");
+    }
+
+    #[test]
+    fn unique_slug_disambiguates_collisions() {
+        let mut counts = HashMap::new();
+        // "Rust" and "rust" both slugify to "rust" and must not collide.
+        assert_eq!(unique_slug("Rust", &mut counts), "rust");
+        assert_eq!(unique_slug("rust", &mut counts), "rust-1");
+        assert_eq!(unique_slug("RUST", &mut counts), "rust-2");
+        // An input that slugifies to empty falls back to a stable base.
+        assert_eq!(unique_slug("!!!", &mut counts), "section");
+    }
+
+    #[test]
+    fn count_words_ignores_code_fences_and_tags() {
+        let content = "One two three\n\n```\nlet ignored = 1;\nmore ignored code;\n```\n\n<p>four five</p>";
+        // Only the five prose words are counted; fenced code and tags are skipped.
+        assert_eq!(count_words(content), 5);
+    }
+
+    #[test]
+    fn reading_metrics_rounds_up_to_at_least_one_minute() {
+        // Empty content still reports a 1-minute minimum.
+        assert_eq!(reading_metrics("", 200), (0, 1));
+        // 201 words at 200 wpm rounds up to 2 minutes.
+        let words = vec!["word"; 201].join(" ");
+        assert_eq!(reading_metrics(&words, 200), (201, 2));
+    }
+}